@@ -0,0 +1,135 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::{AwattarError, AwattarZone, PriceData};
+
+/// A builder for constructing a price query, returned by [`PriceData::builder`].
+///
+/// Chain `.zone(...)`, `.start(...)`, `.end(...)` and `.date(...)` as needed, then call
+/// [`PriceQueryBuilder::build`] to validate the combination and get back a [`PriceQuery`] ready
+/// to run.
+#[derive(Clone, Debug, Default)]
+pub struct PriceQueryBuilder {
+    zone: AwattarZone,
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+    date: Option<NaiveDate>,
+}
+
+impl PriceQueryBuilder {
+    /// Creates a new builder, defaulting to [`AwattarZone::default`] and no start, end or date.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the zone to query. Defaults to [`AwattarZone::Germany`].
+    pub fn zone(mut self, zone: AwattarZone) -> Self {
+        self.zone = zone;
+        self
+    }
+
+    /// Sets the start datetime of the query.
+    pub fn start<TZ: chrono::TimeZone>(mut self, start: DateTime<TZ>) -> Self {
+        self.start = Some(start.with_timezone(&Utc));
+        self
+    }
+
+    /// Sets the end datetime of the query.
+    pub fn end<TZ: chrono::TimeZone>(mut self, end: DateTime<TZ>) -> Self {
+        self.end = Some(end.with_timezone(&Utc));
+        self
+    }
+
+    /// Sets a single date to query, equivalent to [`PriceData::query_date`].
+    ///
+    /// Mutually exclusive with `start`/`end`; [`PriceQueryBuilder::build`] rejects a builder
+    /// that has both set.
+    pub fn date(mut self, date: NaiveDate) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Validates the builder and turns it into a runnable [`PriceQuery`].
+    ///
+    /// Returns [`AwattarError::InvalidQuery`] if `date` is combined with `start` or `end`, or if
+    /// both `start` and `end` are set and `start` is after `end`.
+    pub fn build(self) -> Result<PriceQuery, AwattarError> {
+        if self.date.is_some() && (self.start.is_some() || self.end.is_some()) {
+            return Err(AwattarError::InvalidQuery(
+                "`date` cannot be combined with `start`/`end`".to_owned(),
+            ));
+        }
+
+        if let (Some(start), Some(end)) = (self.start, self.end) {
+            if start > end {
+                return Err(AwattarError::InvalidQuery(
+                    "`start` must not be after `end`".to_owned(),
+                ));
+            }
+        }
+
+        Ok(PriceQuery {
+            zone: self.zone,
+            start: self.start,
+            end: self.end,
+            date: self.date,
+        })
+    }
+}
+
+/// A validated, runnable query built by [`PriceQueryBuilder`].
+#[derive(Clone, Debug)]
+pub struct PriceQuery {
+    zone: AwattarZone,
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+    date: Option<NaiveDate>,
+}
+
+impl PriceQuery {
+    /// Executes this query against the awattar API.
+    pub async fn query(self) -> Result<PriceData, AwattarError> {
+        if let Some(date) = self.date {
+            PriceData::query_date(self.zone, date).await
+        } else {
+            PriceData::query(self.zone, self.start, self.end).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_rejects_start_after_end() {
+        let now = Utc::now();
+
+        let result = PriceQueryBuilder::new()
+            .start(now)
+            .end(now - chrono::Duration::hours(1))
+            .build();
+
+        assert!(matches!(result, Err(AwattarError::InvalidQuery(_))));
+    }
+
+    #[test]
+    fn test_build_rejects_date_combined_with_start_or_end() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+
+        let result = PriceQueryBuilder::new().date(date).start(Utc::now()).build();
+
+        assert!(matches!(result, Err(AwattarError::InvalidQuery(_))));
+    }
+
+    #[test]
+    fn test_build_allows_date_only() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+
+        assert!(PriceQueryBuilder::new().date(date).build().is_ok());
+    }
+
+    #[test]
+    fn test_build_allows_start_only() {
+        assert!(PriceQueryBuilder::new().start(Utc::now()).build().is_ok());
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::{AwattarError, AwattarZone, PriceData, PriceSlot};
+
+/// An incremental cache of previously fetched [`PriceSlot`]s.
+///
+/// Dashboards and other long-running consumers tend to re-query overlapping ranges (e.g. "the
+/// last 48 hours") on every refresh. `PriceCache` keeps the slots it has already seen around and,
+/// on the next [`PriceCache::get`], only fetches what lies outside the span it already holds —
+/// a gap before the earliest cached slot, a gap after the latest one, or neither — merging the
+/// result back in. Slots are cached per [`AwattarZone`].
+///
+/// # Examples
+///
+/// ```
+/// # tokio::runtime::Builder::new_multi_thread().enable_all().build().unwrap().block_on(async {
+/// use awattar_api::{AwattarZone, PriceCache};
+/// use chrono::Utc;
+///
+/// let mut cache = PriceCache::new();
+/// let now = Utc::now();
+///
+/// // Only hits the network the first time; a second call for an overlapping range only
+/// // fetches whatever is newly requested.
+/// let prices = cache.get(AwattarZone::Germany, now, now + chrono::Duration::hours(24)).await.unwrap();
+/// println!("Prices: {:?}", prices);
+/// # });
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PriceCache {
+    cached: HashMap<AwattarZone, Vec<PriceSlot>>,
+}
+
+impl PriceCache {
+    /// Creates a new, empty `PriceCache`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns prices for `zone` between `start` and `end`, fetching only the slots that aren't
+    /// already cached.
+    ///
+    /// If the cache already holds slots for `zone` covering the full range, this returns
+    /// entirely from the cache without any network request. Otherwise a [`PriceData::query`] is
+    /// issued for whatever part of `[start, end]` falls before the earliest cached slot, and
+    /// another for whatever falls after the latest one, and the results are merged into the
+    /// cache, de-duplicated by slot start and kept sorted.
+    ///
+    /// Coverage is judged only by the earliest and latest cached slots. If `start` lies after
+    /// the latest cached slot, the fetch begins at `start`, leaving a gap in the cache between
+    /// the old latest slot and `start`; later requests spanning that gap are served from the
+    /// cache without fetching it.
+    pub async fn get<TZ: TimeZone>(
+        &mut self,
+        zone: AwattarZone,
+        start: DateTime<TZ>,
+        end: DateTime<TZ>,
+    ) -> Result<PriceData, AwattarError> {
+        let start = start.with_timezone(&Utc);
+        let end = end.with_timezone(&Utc);
+
+        let slots = self.cached.entry(zone).or_default();
+
+        let stored_earliest = slots.first().map(PriceSlot::start);
+        let stored_latest = slots.last().map(PriceSlot::end);
+
+        if let Some(earliest) = stored_earliest {
+            if start < earliest {
+                let fetched = PriceData::query(zone, Some(start), Some(earliest)).await?;
+                Self::merge(slots, fetched.into_slots());
+            }
+        }
+
+        let latest_day = stored_latest.map_or(start, |latest| latest.max(start));
+        if latest_day < end {
+            let fetched = PriceData::query(zone, Some(latest_day), Some(end)).await?;
+            Self::merge(slots, fetched.into_slots());
+        }
+
+        let slots = slots
+            .iter()
+            .filter(|slot| slot.end() > start && slot.start() < end)
+            .cloned()
+            .collect();
+
+        Ok(PriceData::from_slots(slots, zone))
+    }
+
+    /// Merges newly fetched slots into `slots`, de-duplicating by `start` and keeping the result
+    /// sorted.
+    fn merge(slots: &mut Vec<PriceSlot>, new_slots: Vec<PriceSlot>) {
+        for slot in new_slots {
+            match slots.iter().position(|existing| existing.start() == slot.start()) {
+                Some(index) => slots[index] = slot,
+                None => slots.push(slot),
+            }
+        }
+
+        slots.sort_by_key(PriceSlot::start);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slot(start_millis: i64, end_millis: i64, price_cents_per_mwh: i32) -> PriceSlot {
+        PriceSlot {
+            start: Utc.timestamp_millis(start_millis),
+            end: Utc.timestamp_millis(end_millis),
+            price_cents_per_mwh,
+        }
+    }
+
+    #[test]
+    fn test_merge_deduplicates_by_start_and_sorts() {
+        let mut slots = vec![slot(3_600_000, 7_200_000, 20)];
+        let new_slots = vec![slot(0, 3_600_000, 10), slot(3_600_000, 7_200_000, 99)];
+
+        PriceCache::merge(&mut slots, new_slots);
+
+        assert_eq!(slots.len(), 2);
+        assert_eq!(slots[0].start(), Utc.timestamp_millis(0));
+        assert_eq!(slots[1].start(), Utc.timestamp_millis(3_600_000));
+        assert_eq!(slots[1].price_cents_per_mwh(), 99);
+    }
+
+    #[test]
+    fn test_merge_appends_without_touching_existing_slots() {
+        let mut slots = vec![slot(0, 3_600_000, 10)];
+        let new_slots = vec![slot(3_600_000, 7_200_000, 20)];
+
+        PriceCache::merge(&mut slots, new_slots);
+
+        assert_eq!(slots.len(), 2);
+        assert_eq!(slots[0].price_cents_per_mwh(), 10);
+        assert_eq!(slots[1].price_cents_per_mwh(), 20);
+    }
+}
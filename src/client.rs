@@ -0,0 +1,83 @@
+use chrono::{DateTime, NaiveDate, TimeZone};
+
+use crate::{AwattarError, AwattarZone, PriceData};
+
+/// A handle that owns a persistent [`reqwest::Client`] and a default [`AwattarZone`].
+///
+/// Constructing a fresh [`reqwest::Client`] for every request (as [`PriceData::query`] does)
+/// throws away the connection pool and any negotiated TLS session. If you're polling prices
+/// repeatedly, build one `AwattarClient` and reuse it so requests share keep-alive connections.
+///
+/// # Examples
+///
+/// ```
+/// # tokio::runtime::Builder::new_multi_thread().enable_all().build().unwrap().block_on(async {
+/// use awattar_api::{AwattarClient, AwattarZone};
+///
+/// let client = AwattarClient::new(AwattarZone::Germany);
+/// let prices = client.query_now().await.unwrap();
+/// println!("Prices: {:?}", prices);
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+pub struct AwattarClient {
+    client: reqwest::Client,
+    zone: AwattarZone,
+}
+
+impl AwattarClient {
+    /// Creates a new `AwattarClient` for the given `zone`, backed by a freshly created
+    /// [`reqwest::Client`].
+    pub fn new(zone: AwattarZone) -> Self {
+        Self::with_client(reqwest::Client::new(), zone)
+    }
+
+    /// Creates a new `AwattarClient` for the given `zone`, reusing an existing
+    /// [`reqwest::Client`] (useful if your application already shares one across requests).
+    pub fn with_client(client: reqwest::Client, zone: AwattarZone) -> Self {
+        Self { client, zone }
+    }
+
+    /// Query prices between the given start- and end-datetime, in this client's zone.
+    ///
+    /// Mirrors [`PriceData::query`], but reuses this handle's [`reqwest::Client`] instead of
+    /// creating a new one.
+    pub async fn query<TZ: TimeZone>(
+        &self,
+        start: Option<DateTime<TZ>>,
+        end: Option<DateTime<TZ>>,
+    ) -> Result<PriceData, AwattarError> {
+        PriceData::query_with_client(&self.client, self.zone, start, end).await
+    }
+
+    /// Query prices for a given date, in this client's zone.
+    ///
+    /// Mirrors [`PriceData::query_date`], but reuses this handle's [`reqwest::Client`].
+    pub async fn query_date(&self, date: NaiveDate) -> Result<PriceData, AwattarError> {
+        let start = date
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_local_timezone(self.zone.timezone())
+            .unwrap();
+        let end = (date + chrono::Duration::days(1))
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_local_timezone(self.zone.timezone())
+            .unwrap();
+
+        self.query(Some(start), Some(end)).await
+    }
+
+    /// Query prices starting now, up to 24 hours into the future.
+    ///
+    /// Mirrors the deprecated free-standing `query_prices_now`, but reuses this handle's
+    /// [`reqwest::Client`].
+    pub async fn query_now(&self) -> Result<PriceData, AwattarError> {
+        self.query::<chrono::Utc>(None, None).await
+    }
+
+    /// Returns the zone this client queries by default.
+    pub fn zone(&self) -> AwattarZone {
+        self.zone
+    }
+}
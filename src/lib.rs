@@ -5,6 +5,18 @@ use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
 use serde::Deserialize;
 use thiserror::Error;
 
+mod builder;
+mod cache;
+mod client;
+mod service;
+mod tariff;
+
+pub use builder::{PriceQuery, PriceQueryBuilder};
+pub use cache::PriceCache;
+pub use client::AwattarClient;
+pub use service::PriceService;
+pub use tariff::{ConsumerPriceSlot, Tariff};
+
 /// A single price slot.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct PriceSlot {
@@ -61,10 +73,40 @@ pub struct PriceData {
     zone: AwattarZone,
 }
 
+/// The cheapest contiguous time window covering a requested duration, as returned by
+/// [`PriceData::cheapest_window`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CheapestWindow {
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    average_price_ct_per_kwh: f64,
+}
+
+impl CheapestWindow {
+    /// DateTime this window starts at.
+    pub fn start(&self) -> DateTime<Utc> {
+        self.start
+    }
+
+    /// Non-inclusive DateTime this window ends at.
+    pub fn end(&self) -> DateTime<Utc> {
+        self.end
+    }
+
+    /// The time-weighted average price over this window, in ct/kWh.
+    pub fn average_price_ct_per_kwh(&self) -> f64 {
+        self.average_price_ct_per_kwh
+    }
+}
+
 impl PriceData {
     /// Query prices from the awattar API between the given start- and end-datetime in the given
     /// zone.
     ///
+    /// This creates a fresh [`reqwest::Client`] for the request. If you're querying prices
+    /// repeatedly, prefer building an [`AwattarClient`] once and reusing it so requests share
+    /// keep-alive connections.
+    ///
     /// # Examples
     ///
     /// ```
@@ -87,7 +129,17 @@ impl PriceData {
         start: Option<DateTime<TZ>>,
         end: Option<DateTime<TZ>>,
     ) -> Result<Self, AwattarError> {
-        let client = reqwest::Client::new();
+        Self::query_with_client(&reqwest::Client::new(), zone, start, end).await
+    }
+
+    /// Query prices using an existing [`reqwest::Client`], so callers that issue many requests
+    /// (like [`AwattarClient`]) can share connection pooling.
+    pub(crate) async fn query_with_client<TZ: TimeZone>(
+        client: &reqwest::Client,
+        zone: AwattarZone,
+        start: Option<DateTime<TZ>>,
+        end: Option<DateTime<TZ>>,
+    ) -> Result<Self, AwattarError> {
         let query_params = [("start", start), ("end", end)]
             .into_iter()
             .filter_map(|(param, timestamp)| {
@@ -223,6 +275,100 @@ impl PriceData {
     pub fn zone(&self) -> AwattarZone {
         self.zone
     }
+
+    /// Finds the cheapest contiguous run of slots covering at least `duration` — the core query
+    /// for "when should I run my dishwasher/charge my car".
+    ///
+    /// This is a two-pointer sliding window over the slots sorted by start time: the right edge
+    /// advances accumulating price weighted by each slot's own length (so DST days with 23 or 25
+    /// hourly slots are handled correctly), and the left edge shrinks once the window covers at
+    /// least `duration`, keeping the window with the lowest *average* price seen so far. Average
+    /// rather than total cost is what matters here: since a minimal covering window can overshoot
+    /// `duration` by a different amount depending on how the available slots line up, comparing
+    /// raw totals would unfairly favor whichever window happens to cover less excess time. A gap
+    /// between two slots resets the window, since the result must be contiguous.
+    ///
+    /// Returns `None` if `duration` is zero or negative, or if no contiguous run of slots covers
+    /// it.
+    pub fn cheapest_window(&self, duration: Duration) -> Option<CheapestWindow> {
+        let target_seconds = duration.num_seconds();
+        if target_seconds <= 0 {
+            return None;
+        }
+
+        let mut slots: Vec<&PriceSlot> = self.slots.iter().collect();
+        slots.sort_by_key(|slot| slot.start());
+
+        let mut best: Option<(usize, usize, f64)> = None;
+        let mut left = 0usize;
+        let mut covered_seconds = 0i64;
+        let mut weighted_cost = 0i64;
+
+        for right in 0..slots.len() {
+            if right > 0 && slots[right].start() != slots[right - 1].end() {
+                left = right;
+                covered_seconds = 0;
+                weighted_cost = 0;
+            }
+
+            let slot_seconds = (slots[right].end() - slots[right].start()).num_seconds();
+            covered_seconds += slot_seconds;
+            weighted_cost += slots[right].price_cents_per_mwh() as i64 * slot_seconds;
+
+            while left < right {
+                let left_seconds = (slots[left].end() - slots[left].start()).num_seconds();
+                if covered_seconds - left_seconds < target_seconds {
+                    break;
+                }
+
+                covered_seconds -= left_seconds;
+                weighted_cost -= slots[left].price_cents_per_mwh() as i64 * left_seconds;
+                left += 1;
+            }
+
+            if covered_seconds >= target_seconds {
+                let average_cost = weighted_cost as f64 / covered_seconds as f64;
+                let is_cheaper = best
+                    .map(|(_, _, best_average)| average_cost < best_average)
+                    .unwrap_or(true);
+
+                if is_cheaper {
+                    best = Some((left, right, average_cost));
+                }
+            }
+        }
+
+        best.map(|(left, right, average_cost)| CheapestWindow {
+            start: slots[left].start(),
+            end: slots[right].end(),
+            average_price_ct_per_kwh: average_cost / 1000.0,
+        })
+    }
+
+    /// Returns a [`PriceQueryBuilder`] for constructing a query with chainable setters, instead
+    /// of juggling positional `Option<DateTime<_>>` arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_multi_thread().enable_all().build().unwrap().block_on(async {
+    /// use awattar_api::{AwattarZone, PriceData};
+    /// use chrono::Utc;
+    ///
+    /// let prices = PriceData::builder()
+    ///     .zone(AwattarZone::Germany)
+    ///     .start(Utc::now())
+    ///     .build()
+    ///     .unwrap()
+    ///     .query()
+    ///     .await
+    ///     .unwrap();
+    /// println!("Prices: {:?}", prices);
+    /// # });
+    /// ```
+    pub fn builder() -> PriceQueryBuilder {
+        PriceQueryBuilder::new()
+    }
 }
 
 /// Struct for deserialzing time-slots from the awattar API.
@@ -247,13 +393,15 @@ pub enum AwattarError {
     Reqwest(#[from] reqwest::Error),
     #[error("API responded with an unsupported response")]
     UnsupportedResponse(String),
+    #[error("invalid query: {0}")]
+    InvalidQuery(String),
 }
 
 /// Zone for awattar prices.
 ///
 /// Currently supports Austria and Germany, but could expand in the future as Germany might
 /// split their price zones or awattar adds support for further countries.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum AwattarZone {
     /// Prices for Austria
     Austria,
@@ -285,6 +433,13 @@ impl AwattarZone {
     }
 }
 
+impl Default for AwattarZone {
+    /// Defaults to [`AwattarZone::Germany`], the zone used in this crate's examples.
+    fn default() -> Self {
+        AwattarZone::Germany
+    }
+}
+
 /// Query prices from the API in the given `zone` with an optional `start` and `end`
 /// DateTime.
 ///
@@ -347,4 +502,97 @@ mod tests {
 
         assert_eq!(slot.price_cents_per_mwh(), -4209);
     }
+
+    fn slot(start_millis: i64, end_millis: i64, price_cents_per_mwh: i32) -> PriceSlot {
+        PriceSlot {
+            start: Utc.timestamp_millis(start_millis),
+            end: Utc.timestamp_millis(end_millis),
+            price_cents_per_mwh,
+        }
+    }
+
+    #[test]
+    fn test_cheapest_window_picks_min_average_cost_window() {
+        // Four contiguous hourly slots, prices 10/5/3/8 ct/MWh.
+        let hour = 3_600_000;
+        let data = PriceData::from_slots(
+            vec![
+                slot(0, hour, 10),
+                slot(hour, 2 * hour, 5),
+                slot(2 * hour, 3 * hour, 3),
+                slot(3 * hour, 4 * hour, 8),
+            ],
+            AwattarZone::Germany,
+        );
+
+        let window = data.cheapest_window(Duration::hours(2)).unwrap();
+
+        assert_eq!(window.start(), Utc.timestamp_millis(hour));
+        assert_eq!(window.end(), Utc.timestamp_millis(3 * hour));
+    }
+
+    #[test]
+    fn test_cheapest_window_normalizes_by_covered_duration() {
+        // A single 120-minute slot at a good rate vs. a separate, non-contiguous single
+        // 91-minute slot at a worse rate. Both cover the requested 90 minutes, but comparing raw
+        // totals (120min * 50 = 6000 vs. 91min * 60 = 5460) would wrongly prefer the worse-rate
+        // slot just because it covers less excess time.
+        let minute = 60_000;
+        let cheap = slot(0, 120 * minute, 50);
+        let pricier = slot(200 * minute, (200 + 91) * minute, 60);
+
+        let data = PriceData::from_slots(vec![cheap.clone(), pricier], AwattarZone::Germany);
+
+        let window = data.cheapest_window(Duration::minutes(90)).unwrap();
+
+        assert_eq!(window.start(), cheap.start());
+        assert_eq!(window.end(), cheap.end());
+    }
+
+    #[test]
+    fn test_cheapest_window_weighs_variable_length_slots() {
+        // A short, expensive slot followed by a longer, cheap one that alone already covers the
+        // requested duration.
+        let minute = 60_000;
+        let short = slot(0, 30 * minute, 100);
+        let long = slot(30 * minute, 120 * minute, 10);
+
+        let data = PriceData::from_slots(vec![short, long.clone()], AwattarZone::Germany);
+
+        let window = data.cheapest_window(Duration::minutes(90)).unwrap();
+
+        assert_eq!(window.start(), long.start());
+        assert_eq!(window.end(), long.end());
+        assert!((window.average_price_ct_per_kwh() - 0.01).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cheapest_window_returns_none_across_a_gap() {
+        let hour = 3_600_000;
+        // A one-hour gap between the two slots, so neither a 2-hour window nor a merge across
+        // them is contiguous.
+        let data = PriceData::from_slots(
+            vec![slot(0, hour, 10), slot(2 * hour, 3 * hour, 20)],
+            AwattarZone::Germany,
+        );
+
+        assert!(data.cheapest_window(Duration::hours(2)).is_none());
+    }
+
+    #[test]
+    fn test_cheapest_window_returns_none_when_data_is_insufficient() {
+        let data =
+            PriceData::from_slots(vec![slot(0, 3_600_000, 10)], AwattarZone::Germany);
+
+        assert!(data.cheapest_window(Duration::hours(2)).is_none());
+    }
+
+    #[test]
+    fn test_cheapest_window_returns_none_for_zero_or_negative_duration() {
+        let data =
+            PriceData::from_slots(vec![slot(0, 3_600_000, 10)], AwattarZone::Germany);
+
+        assert!(data.cheapest_window(Duration::seconds(0)).is_none());
+        assert!(data.cheapest_window(Duration::seconds(-1)).is_none());
+    }
 }
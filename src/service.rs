@@ -0,0 +1,225 @@
+use std::time::Duration as StdDuration;
+
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use tokio::sync::{oneshot, watch};
+use tokio::task::JoinHandle;
+
+use crate::{AwattarClient, AwattarZone, PriceData};
+
+/// Default interval between price refreshes, used unless the aWATTar daily publish time is
+/// sooner.
+const DEFAULT_REFRESH_INTERVAL: StdDuration = StdDuration::from_secs(60 * 60);
+
+/// Hour (UTC) at which aWATTar publishes next-day prices. We refresh shortly after this time so
+/// new prices show up without waiting for the regular interval to elapse.
+const DAILY_PUBLISH_HOUR_UTC: u32 = 13;
+
+/// A background service that keeps a [`PriceData`] snapshot up to date.
+///
+/// aWATTar publishes next-day prices once per day, so rather than polling manually, spawn a
+/// `PriceService` once and subscribe to it: [`PriceService::start`] launches a tokio task that
+/// refreshes prices on an interval (and re-schedules itself to run shortly after the daily
+/// publish time), while [`PriceService::subscribe`] hands out a [`tokio::sync::watch::Receiver`]
+/// that always observes the latest snapshot.
+///
+/// # Examples
+///
+/// ```no_run
+/// # tokio::runtime::Builder::new_multi_thread().enable_all().build().unwrap().block_on(async {
+/// use awattar_api::{AwattarZone, PriceService};
+///
+/// let mut service = PriceService::new(AwattarZone::Germany);
+/// service.start();
+///
+/// let mut prices = service.subscribe();
+/// prices.changed().await.unwrap();
+/// println!("Prices: {:?}", prices.borrow());
+///
+/// service.stop_and_await().await;
+/// # });
+/// ```
+pub struct PriceService {
+    zone: AwattarZone,
+    client: AwattarClient,
+    refresh_interval: StdDuration,
+    tx: watch::Sender<Option<PriceData>>,
+    rx: watch::Receiver<Option<PriceData>>,
+    task: Option<JoinHandle<()>>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl PriceService {
+    /// Creates a new, not-yet-started `PriceService` for `zone`.
+    pub fn new(zone: AwattarZone) -> Self {
+        Self::with_client(AwattarClient::new(zone), zone)
+    }
+
+    /// Creates a new `PriceService`, reusing an existing [`AwattarClient`].
+    pub fn with_client(client: AwattarClient, zone: AwattarZone) -> Self {
+        let (tx, rx) = watch::channel(None);
+
+        Self {
+            zone,
+            client,
+            refresh_interval: DEFAULT_REFRESH_INTERVAL,
+            tx,
+            rx,
+            task: None,
+            shutdown: None,
+        }
+    }
+
+    /// Sets the interval at which this service refreshes prices while idle. Defaults to one
+    /// hour.
+    ///
+    /// This only bounds the *maximum* time between refreshes; the service always refreshes
+    /// sooner if the daily publish time falls within the interval.
+    pub fn refresh_interval(mut self, interval: StdDuration) -> Self {
+        self.refresh_interval = interval;
+        self
+    }
+
+    /// Subscribes to the latest [`PriceData`] snapshot.
+    ///
+    /// The receiver initially holds `None` until the first successful refresh. Use
+    /// [`tokio::sync::watch::Receiver::changed`] to wait for updates.
+    pub fn subscribe(&self) -> watch::Receiver<Option<PriceData>> {
+        self.rx.clone()
+    }
+
+    /// Starts the background refresh task, if it isn't already running.
+    ///
+    /// Each refresh queries from the start of today through the end of tomorrow, in this
+    /// service's zone, so newly published next-day prices show up as soon as they're available.
+    pub fn start(&mut self) {
+        if self.task.is_some() {
+            return;
+        }
+
+        let (shutdown_tx, mut shutdown_rx) = oneshot::channel();
+        let client = self.client.clone();
+        let zone = self.zone;
+        let refresh_interval = self.refresh_interval;
+        let tx = self.tx.clone();
+
+        let task = tokio::spawn(async move {
+            loop {
+                let today = Utc::now().with_timezone(&zone.timezone()).date_naive();
+                let start = today
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(zone.timezone())
+                    .unwrap();
+                let end = (today + ChronoDuration::days(2))
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
+                    .and_local_timezone(zone.timezone())
+                    .unwrap();
+
+                if let Ok(prices) = client.query(Some(start), Some(end)).await {
+                    let _ = tx.send(Some(prices));
+                }
+
+                let sleep = tokio::time::sleep(Self::next_refresh_delay(refresh_interval));
+                tokio::pin!(sleep);
+
+                tokio::select! {
+                    _ = &mut sleep => {}
+                    _ = &mut shutdown_rx => break,
+                }
+            }
+        });
+
+        self.task = Some(task);
+        self.shutdown = Some(shutdown_tx);
+    }
+
+    /// Signals the background task to stop without waiting for it to finish.
+    pub fn stop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        self.task = None;
+    }
+
+    /// Signals the background task to stop and waits for it to actually finish.
+    pub async fn stop_and_await(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+
+        if let Some(task) = self.task.take() {
+            let _ = task.await;
+        }
+    }
+
+    /// Returns the zone this service refreshes.
+    pub fn zone(&self) -> AwattarZone {
+        self.zone
+    }
+
+    /// Computes how long to sleep before the next refresh: either `refresh_interval`, or the
+    /// time until the next daily publish, whichever is sooner.
+    fn next_refresh_delay(refresh_interval: StdDuration) -> StdDuration {
+        let now = Utc::now();
+        let until_publish = Self::next_daily_publish(now) - now;
+
+        until_publish
+            .to_std()
+            .unwrap_or(refresh_interval)
+            .min(refresh_interval)
+    }
+
+    /// Returns the next datetime (UTC) strictly after `now` at which aWATTar is expected to have
+    /// published new prices.
+    fn next_daily_publish(now: DateTime<Utc>) -> DateTime<Utc> {
+        let todays_publish = now
+            .date_naive()
+            .and_hms_opt(DAILY_PUBLISH_HOUR_UTC, 5, 0)
+            .unwrap()
+            .and_local_timezone(Utc)
+            .unwrap();
+
+        if now < todays_publish {
+            todays_publish
+        } else {
+            todays_publish + ChronoDuration::days(1)
+        }
+    }
+}
+
+impl Drop for PriceService {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_next_daily_publish_same_day_before_publish_time() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap();
+
+        assert_eq!(
+            PriceService::next_daily_publish(now),
+            Utc.with_ymd_and_hms(2024, 1, 1, DAILY_PUBLISH_HOUR_UTC, 5, 0)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_next_daily_publish_rolls_over_after_publish_time() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 23, 0, 0).unwrap();
+
+        assert_eq!(
+            PriceService::next_daily_publish(now),
+            Utc.with_ymd_and_hms(2024, 1, 2, DAILY_PUBLISH_HOUR_UTC, 5, 0)
+                .unwrap()
+        );
+    }
+}
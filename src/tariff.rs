@@ -0,0 +1,171 @@
+use chrono::{DateTime, Utc};
+
+use crate::{PriceData, PriceSlot};
+
+/// Describes the components an electricity bill adds on top of the raw exchange spot price.
+///
+/// `price_cents_per_mwh` on a [`PriceSlot`] is the exchange spot price; what a household
+/// actually pays is that price plus a supplier markup, fixed grid/levy surcharges, and VAT.
+/// Combine a `Tariff` with [`PriceSlot::consumer_price`] or [`crate::PriceData::with_tariff`] to
+/// get the ct/kWh a customer is actually billed.
+///
+/// # Examples
+///
+/// ```
+/// use awattar_api::Tariff;
+///
+/// let tariff = Tariff::new(3.0, 8.5, 19.0).with_base_fee(4.5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tariff {
+    /// Supplier markup on the spot price, in percent.
+    markup_percent: f64,
+    /// Fixed grid fees, levies and other surcharges, in ct/kWh, added after the markup.
+    surcharge_ct_per_kwh: f64,
+    /// Value-added tax applied to the final price, in percent.
+    vat_percent: f64,
+    /// Optional fixed monthly base fee, in Euros, not reflected in the per-kWh price.
+    base_fee_per_month: Option<f64>,
+}
+
+impl Tariff {
+    /// Creates a new `Tariff` from its percentage markup, fixed ct/kWh surcharges and VAT rate.
+    pub fn new(markup_percent: f64, surcharge_ct_per_kwh: f64, vat_percent: f64) -> Self {
+        Self {
+            markup_percent,
+            surcharge_ct_per_kwh,
+            vat_percent,
+            base_fee_per_month: None,
+        }
+    }
+
+    /// Sets a fixed monthly base fee, in Euros.
+    pub fn with_base_fee(mut self, base_fee_per_month: f64) -> Self {
+        self.base_fee_per_month = Some(base_fee_per_month);
+        self
+    }
+
+    /// Returns the supplier markup on the spot price, in percent.
+    pub fn markup_percent(&self) -> f64 {
+        self.markup_percent
+    }
+
+    /// Returns the fixed grid fees, levies and other surcharges, in ct/kWh.
+    pub fn surcharge_ct_per_kwh(&self) -> f64 {
+        self.surcharge_ct_per_kwh
+    }
+
+    /// Returns the VAT rate applied to the final price, in percent.
+    pub fn vat_percent(&self) -> f64 {
+        self.vat_percent
+    }
+
+    /// Returns the fixed monthly base fee, in Euros, if one was set.
+    pub fn base_fee_per_month(&self) -> Option<f64> {
+        self.base_fee_per_month
+    }
+}
+
+impl PriceSlot {
+    /// Computes the effective ct/kWh a consumer on `tariff` pays for this slot's spot price.
+    ///
+    /// The markup is applied to the spot price first, then the fixed surcharge is added, and
+    /// VAT is applied last, matching how German/Austrian electricity bills are typically
+    /// composed. Any [`Tariff::base_fee_per_month`] is not included, since it isn't a per-kWh
+    /// rate.
+    pub fn consumer_price(&self, tariff: &Tariff) -> f64 {
+        let spot_ct_per_kwh = self.price_cents_per_mwh() as f64 / 1000.0;
+        let with_markup_and_surcharge =
+            spot_ct_per_kwh * (1.0 + tariff.markup_percent / 100.0) + tariff.surcharge_ct_per_kwh;
+
+        with_markup_and_surcharge * (1.0 + tariff.vat_percent / 100.0)
+    }
+}
+
+/// A single slot's effective consumer price, as returned by [`PriceData::with_tariff`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsumerPriceSlot {
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    price_ct_per_kwh: f64,
+}
+
+impl ConsumerPriceSlot {
+    /// DateTime this slot is valid from.
+    pub fn start(&self) -> DateTime<Utc> {
+        self.start
+    }
+
+    /// Non-inclusive DateTime this slot is valid to.
+    pub fn end(&self) -> DateTime<Utc> {
+        self.end
+    }
+
+    /// The effective price a consumer pays for this slot, in ct/kWh.
+    pub fn price_ct_per_kwh(&self) -> f64 {
+        self.price_ct_per_kwh
+    }
+}
+
+impl PriceData {
+    /// Turns this instance's raw spot-price slots into consumer prices under `tariff`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_multi_thread().enable_all().build().unwrap().block_on(async {
+    /// use awattar_api::{AwattarZone, PriceData, Tariff};
+    /// use chrono::Local;
+    ///
+    /// let prices = PriceData::query_date(AwattarZone::Germany, Local::today().naive_local())
+    ///     .await
+    ///     .unwrap();
+    /// let tariff = Tariff::new(3.0, 8.5, 19.0);
+    /// let consumer_prices = prices.with_tariff(&tariff);
+    /// println!("Consumer prices: {:?}", consumer_prices);
+    /// # });
+    /// ```
+    pub fn with_tariff(&self, tariff: &Tariff) -> Vec<ConsumerPriceSlot> {
+        self.slots_iter()
+            .map(|slot| ConsumerPriceSlot {
+                start: slot.start(),
+                end: slot.end(),
+                price_ct_per_kwh: slot.consumer_price(tariff),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn slot(price_cents_per_mwh: i32) -> PriceSlot {
+        PriceSlot {
+            start: Utc.timestamp_millis(0),
+            end: Utc.timestamp_millis(3_600_000),
+            price_cents_per_mwh,
+        }
+    }
+
+    #[test]
+    fn test_consumer_price_applies_markup_surcharge_then_vat() {
+        // 5000 cents/MWh = 5.0 ct/kWh spot price.
+        let slot = slot(5000);
+        let tariff = Tariff::new(10.0, 2.0, 20.0);
+
+        // spot 5.0 -> +10% markup = 5.5 -> +2 ct surcharge = 7.5 -> +20% VAT = 9.0
+        let price = slot.consumer_price(&tariff);
+
+        assert!((price - 9.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_consumer_price_zero_tariff_is_identity() {
+        let slot = slot(5000);
+        let tariff = Tariff::new(0.0, 0.0, 0.0);
+
+        assert!((slot.consumer_price(&tariff) - 5.0).abs() < 1e-9);
+    }
+}